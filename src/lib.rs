@@ -45,14 +45,64 @@ pub struct MultiIter<T> {
 	empty: bool,
 	index: usize,
 	items: IterList<T>,
+	stop_on_empty: bool,
+	weights: Vec<usize>,
+	quota: usize,
 }
 
 impl<T> MultiIter<T> {
 	pub fn new(items: IterList<T>) -> MultiIter<T> {
+		let weights = vec![1; items.len()];
 		MultiIter {
 			empty: false,
 			index: 0,
 			items: items,
+			stop_on_empty: false,
+			weights: weights,
+			quota: 0,
+		}
+	}
+
+	/// Build a `MultiIter` that stops as soon as any single source is
+	/// exhausted, instead of padding the output with the sources that
+	/// still have elements left.
+	///
+	/// This keeps every round perfectly aligned, matching itertools'
+	/// `interleave_shortest` behavior.
+	pub fn shortest(items: IterList<T>) -> MultiIter<T> {
+		let weights = vec![1; items.len()];
+		MultiIter {
+			empty: false,
+			index: 0,
+			items: items,
+			stop_on_empty: true,
+			weights: weights,
+			quota: 0,
+		}
+	}
+
+	/// Build a weighted round-robin `MultiIter`, drawing `weight`
+	/// elements from a source before rotating to the next one.
+	///
+	/// A weight of `0` skips the source entirely. Within a round a source
+	/// can run up to `weight` `next()` calls ahead of the sources after
+	/// it, so the one-call lag of the default mode does not hold here.
+	pub fn weighted(items: Vec<(Iter<T>, usize)>) -> MultiIter<T> {
+		let mut sources = vec![];
+		let mut weights = vec![];
+		for (item, weight) in items {
+			if weight > 0 {
+				sources.push(item);
+				weights.push(weight);
+			}
+		}
+		MultiIter {
+			empty: false,
+			index: 0,
+			items: sources,
+			stop_on_empty: false,
+			weights: weights,
+			quota: 0,
 		}
 	}
 
@@ -62,6 +112,7 @@ impl<T> MultiIter<T> {
 	/// reset when the other iterators have been exhausted.
 	pub fn push(&mut self, item: Iter<T>) {
 		self.items.push(item);
+		self.weights.push(1);
 	}
 }
 
@@ -71,6 +122,9 @@ impl<T> Default for MultiIter<T> {
 			empty: false,
 			index: 0,
 			items: vec![],
+			stop_on_empty: false,
+			weights: vec![],
+			quota: 0,
 		}
 	}
 }
@@ -79,16 +133,38 @@ impl<T> Iterator for MultiIter<T> {
 	type Item = T;
 	fn next(&mut self) -> Option<Self::Item> {
 		loop {
-			if let Some(iterator) = self.items.get_mut(self.index) {
-				if let Some(value) = iterator.next() {
+			if self.index < self.items.len() {
+				if self.quota == 0 {
+					self.quota = self.weights[self.index];
+				}
+				let drawn = self.items[self.index].next();
+				if let Some(value) = drawn {
 					self.empty = false;
-					self.index += 1;
+					self.quota -= 1;
+					if self.quota == 0 {
+						self.index += 1;
+					}
 					return Some(value);
+				} else if self.stop_on_empty {
+					// Drop every source so later calls keep returning `None`.
+					self.items.clear();
+					self.weights.clear();
+					return None;
 				} else {
-					self.index += 1;
+					// Drop the exhausted source instead of leaving it in
+					// rotation: it will never yield again. `remove` (not
+					// `swap_remove`) is used so the remaining sources keep
+					// their original relative order -- swapping the last
+					// source into this slot would let it jump ahead of
+					// sources it used to follow, changing the observable
+					// interleave sequence.
+					self.quota = 0;
+					drop(self.items.remove(self.index));
+					self.weights.remove(self.index);
 				}
 			} else {
 				self.index = 0;
+				self.quota = 0;
 				if self.empty {
 					return None;
 				} else {
@@ -97,8 +173,82 @@ impl<T> Iterator for MultiIter<T> {
 			}
 		}
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.stop_on_empty {
+			self.shortest_size_hint()
+		} else {
+			self.items.iter().fold((0, Some(0)), |(lo, hi), item| {
+				let (item_lo, item_hi) = item.size_hint();
+				let lo = lo.saturating_add(item_lo);
+				let hi = match (hi, item_hi) {
+					(Some(a), Some(b)) => Some(a.saturating_add(b)),
+					_ => None,
+				};
+				(lo, hi)
+			})
+		}
+	}
+}
+
+impl<T> MultiIter<T> {
+	/// In `shortest` mode the iterator stops the moment the source at the
+	/// current `index` comes up empty, so summing every source's bounds
+	/// (as the padding-all-sources mode does) wildly overcounts. Only the
+	/// shortest source's remaining length matters, offset by how many of
+	/// the other sources get visited first in this pass through `items`.
+	///
+	/// All public constructors give a `shortest` `MultiIter` uniform
+	/// weight 1, so the arithmetic below ignores `weights`/`quota`.
+	fn shortest_size_hint(&self) -> (usize, Option<usize>) {
+		let n = self.items.len();
+		if n == 0 {
+			return (0, Some(0));
+		}
+		let bounds: Vec<(usize, Option<usize>)> = self.items.iter().map(|item| item.size_hint()).collect();
+		let min_lo = bounds.iter().map(|&(lo, _)| lo).min().unwrap_or(0);
+		let min_hi = bounds.iter().fold(None, |acc: Option<usize>, &(_, hi)| {
+			match (acc, hi) {
+				(None, x) => x,
+				(Some(a), Some(b)) => Some(::std::cmp::min(a, b)),
+				(Some(a), None) => Some(a),
+			}
+		});
+		let start = self.index % n;
+		if bounds.iter().all(|&(lo, hi)| hi == Some(lo)) {
+			let exact = bounds.iter().map(|&(lo, _)| lo).collect::<Vec<_>>();
+			let offset = (0..n).find(|&step| exact[(start + step) % n] == min_lo).unwrap_or(0);
+			let total = min_lo.saturating_mul(n).saturating_add(offset);
+			return (total, Some(total));
+		}
+		let lower = min_lo.saturating_mul(n);
+		let upper = min_hi.map(|hi| hi.saturating_mul(n).saturating_add(n - 1));
+		(lower, upper)
+	}
+}
+
+impl<T> MultiIter<T> {
+	/// Returns the exact remaining length when every source's `size_hint`
+	/// bounds agree, or `None` when a source (e.g. a `filter`) only
+	/// exposes an inexact bound.
+	///
+	/// `MultiIter` cannot implement `ExactSizeIterator` itself: `items`
+	/// holds type-erased `Iterator` trait objects, so there is no way to
+	/// know at the type level whether every source is exact, and the
+	/// trait requires `len()` to be exact for *every* instance, not just
+	/// the common case of ranges and vecs.
+	pub fn exact_len(&self) -> Option<usize> {
+		let (lower, upper) = self.size_hint();
+		if Some(lower) == upper {
+			Some(lower)
+		} else {
+			None
+		}
+	}
 }
 
+impl<T> ::std::iter::FusedIterator for MultiIter<T> {}
+
 /// Main macro for creating a MultiIter
 #[macro_export]
 macro_rules! interleave {
@@ -122,9 +272,289 @@ macro_rules! interleave {
 	});
 }
 
+/// Variant of `interleave!` that stops at the first exhausted source,
+/// instead of padding the output with whatever sources remain.
+#[macro_export]
+macro_rules! interleave_shortest {
+	($($e:expr),+,) => ({ interleave_shortest!($($e),*) });
+	($($e:expr),+) => ({
+		let mut temporary: IterList<_> = vec![];
+		$(
+			temporary.push(Box::new($e));
+		);*
+		MultiIter::shortest(temporary)
+	});
+	() => ( MultiIter::shortest(IterList::<_>::default()) );
+	($t:ty;) => ( MultiIter::shortest(IterList::<$t>::default()) );
+	($t:ty; $($e:expr),+,) => ( interleave_shortest!($t; $($e),*) );
+	($t:ty; $($e:expr),+) => ({
+		let mut temporary: IterList<$t> = vec![];
+		$(
+			temporary.push(Box::new($e));
+		)*
+		MultiIter::shortest(temporary)
+	});
+}
+
+/// Build a weighted `MultiIter` that draws `weight` elements from each
+/// source before rotating, e.g. `interleave_weighted![(a, 3), (b, 1)]`.
+#[macro_export]
+macro_rules! interleave_weighted {
+	($(($e:expr, $w:expr)),+,) => ({ interleave_weighted!($(($e, $w)),*) });
+	($(($e:expr, $w:expr)),+) => ({
+		let mut temporary: Vec<(Iter<_>, usize)> = vec![];
+		$(
+			temporary.push((Box::new($e), $w));
+		);*
+		MultiIter::weighted(temporary)
+	});
+}
+
+/// A boxed iterator that can also be driven from the back, used by
+/// `MultiIterBack`. Sources must also be `ExactSizeIterator` so the
+/// two cursors can tell exactly when they have met in the middle.
+pub trait DoubleEndedExactIterator<T>: DoubleEndedIterator<Item = T> + ExactSizeIterator {}
+impl<T, I: DoubleEndedIterator<Item = T> + ExactSizeIterator> DoubleEndedExactIterator<T> for I {}
+
+#[allow(dead_code)]
+pub type DoubleEndedIter<T> = Box<DoubleEndedExactIterator<T>>;
+
+/// Vector of boxed double-ended iterator traits
+pub type DoubleEndedIterList<T> = Vec<DoubleEndedIter<T>>;
+
+/// Holds the state of an interleave iterator whose sources can be
+/// driven from either end, so that `iter.rev()` yields a tail-to-head
+/// round-robin. The forward cursor `index` and the backward cursor
+/// `back_index` walk independently, and each `next`/`next_back` call
+/// re-derives the total remaining element count from the sources'
+/// `ExactSizeIterator::len` so that the two cursors never double-yield
+/// once they meet in the middle.
+pub struct MultiIterBack<T> {
+	index: usize,
+	back_index: usize,
+	items: DoubleEndedIterList<T>,
+}
+
+impl<T> MultiIterBack<T> {
+	pub fn new(items: DoubleEndedIterList<T>) -> MultiIterBack<T> {
+		let back_index = items.len().saturating_sub(1);
+		MultiIterBack {
+			index: 0,
+			back_index: back_index,
+			items: items,
+		}
+	}
+
+	/// Add a new iterator to stack of iterables.
+	///
+	/// Should only be used when setting up, does not
+	/// reset when the other iterators have been exhausted.
+	pub fn push(&mut self, item: DoubleEndedIter<T>) {
+		self.items.push(item);
+	}
+
+	fn total_remaining(&self) -> usize {
+		self.items.iter().map(|item| item.len()).sum()
+	}
+}
+
+impl<T> Iterator for MultiIterBack<T> {
+	type Item = T;
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.total_remaining() == 0 {
+			return None;
+		}
+		loop {
+			if self.index >= self.items.len() {
+				self.index = 0;
+			}
+			if let Some(value) = self.items[self.index].next() {
+				self.index += 1;
+				return Some(value);
+			} else {
+				self.index += 1;
+			}
+		}
+	}
+}
+
+impl<T> DoubleEndedIterator for MultiIterBack<T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.items.is_empty() || self.total_remaining() == 0 {
+			return None;
+		}
+		loop {
+			let current = self.back_index;
+			if let Some(value) = self.items[current].next_back() {
+				self.back_index = if current == 0 { self.items.len() - 1 } else { current - 1 };
+				return Some(value);
+			} else {
+				self.back_index = if current == 0 { self.items.len() - 1 } else { current - 1 };
+			}
+		}
+	}
+}
+
+/// Build a `MultiIterBack` whose sources support `DoubleEndedIterator`
+/// and `ExactSizeIterator`.
+#[macro_export]
+macro_rules! interleave_back {
+	($($e:expr),+,) => ({ interleave_back!($($e),*) });
+	($($e:expr),+) => ({
+		let mut temporary: DoubleEndedIterList<_> = vec![];
+		$(
+			temporary.push(Box::new($e));
+		);*
+		MultiIterBack::new(temporary)
+	});
+}
+
+/// Holds the state of the k-way sorted merge iterator.
+///
+/// Unlike `MultiIter`, which round-robins regardless of ordering,
+/// `MergeIter` assumes each source is already sorted and lazily
+/// produces a single globally sorted output, popping whichever
+/// source currently holds the smallest head element.
+pub struct MergeIter<T, F> {
+	items: IterList<T>,
+	heap: Vec<(T, usize)>,
+	compare: F,
+}
+
+/// Order two heap entries by `compare`, breaking ties on source index so
+/// that equal heads still come out in stable, source-order.
+fn heap_less<T, F: FnMut(&T, &T) -> ::std::cmp::Ordering>(compare: &mut F, a: &(T, usize), b: &(T, usize)) -> bool {
+	match compare(&a.0, &b.0) {
+		::std::cmp::Ordering::Less => true,
+		::std::cmp::Ordering::Greater => false,
+		::std::cmp::Ordering::Equal => a.1 < b.1,
+	}
+}
+
+fn sift_up<T, F: FnMut(&T, &T) -> ::std::cmp::Ordering>(heap: &mut Vec<(T, usize)>, compare: &mut F, mut index: usize) {
+	while index > 0 {
+		let parent = (index - 1) / 2;
+		if heap_less(compare, &heap[index], &heap[parent]) {
+			heap.swap(index, parent);
+			index = parent;
+		} else {
+			break;
+		}
+	}
+}
+
+fn sift_down<T, F: FnMut(&T, &T) -> ::std::cmp::Ordering>(heap: &mut Vec<(T, usize)>, compare: &mut F, mut index: usize) {
+	let len = heap.len();
+	loop {
+		let left = 2 * index + 1;
+		let right = 2 * index + 2;
+		let mut smallest = index;
+		if left < len && heap_less(compare, &heap[left], &heap[smallest]) {
+			smallest = left;
+		}
+		if right < len && heap_less(compare, &heap[right], &heap[smallest]) {
+			smallest = right;
+		}
+		if smallest == index {
+			break;
+		}
+		heap.swap(index, smallest);
+		index = smallest;
+	}
+}
+
+impl<T, F: FnMut(&T, &T) -> ::std::cmp::Ordering> MergeIter<T, F> {
+	/// Build a merge iterator driven by a custom comparator.
+	///
+	/// Pulls the first element from each source and seeds the heap
+	/// with the non-empty ones; sources that are already empty at
+	/// construction are simply never inserted.
+	pub fn new_by(items: IterList<T>, compare: F) -> MergeIter<T, F> {
+		let mut merge = MergeIter {
+			items: items,
+			heap: vec![],
+			compare: compare,
+		};
+		for index in 0..merge.items.len() {
+			if let Some(head) = merge.items[index].next() {
+				merge.heap.push((head, index));
+				let last = merge.heap.len() - 1;
+				sift_up(&mut merge.heap, &mut merge.compare, last);
+			}
+		}
+		merge
+	}
+
+	fn pop_min(&mut self) -> Option<(T, usize)> {
+		if self.heap.is_empty() {
+			return None;
+		}
+		let last = self.heap.len() - 1;
+		self.heap.swap(0, last);
+		let popped = self.heap.pop();
+		if !self.heap.is_empty() {
+			sift_down(&mut self.heap, &mut self.compare, 0);
+		}
+		popped
+	}
+}
+
+impl<T: Ord> MergeIter<T, fn(&T, &T) -> ::std::cmp::Ordering> {
+	/// Build a merge iterator that orders elements with `Ord::cmp`.
+	pub fn new(items: IterList<T>) -> MergeIter<T, fn(&T, &T) -> ::std::cmp::Ordering> {
+		fn cmp_by_ord<T: Ord>(a: &T, b: &T) -> ::std::cmp::Ordering {
+			a.cmp(b)
+		}
+		MergeIter::new_by(items, cmp_by_ord::<T>)
+	}
+}
+
+impl<T, F: FnMut(&T, &T) -> ::std::cmp::Ordering> Iterator for MergeIter<T, F> {
+	type Item = T;
+	fn next(&mut self) -> Option<Self::Item> {
+		let (value, source) = match self.pop_min() {
+			Some(entry) => entry,
+			None => return None,
+		};
+		if let Some(next_head) = self.items[source].next() {
+			self.heap.push((next_head, source));
+			let last = self.heap.len() - 1;
+			sift_up(&mut self.heap, &mut self.compare, last);
+		}
+		Some(value)
+	}
+}
+
+/// Build a `MergeIter` over already-sorted sources, ordered by `Ord::cmp`.
+#[macro_export]
+macro_rules! merge {
+	($($e:expr),+,) => ({ merge!($($e),*) });
+	($($e:expr),+) => ({
+		let mut temporary: IterList<_> = vec![];
+		$(
+			temporary.push(Box::new($e));
+		);*
+		MergeIter::new(temporary)
+	});
+}
+
+/// Build a `MergeIter` over already-sorted sources, ordered by a custom
+/// `FnMut(&T, &T) -> std::cmp::Ordering` comparator given first.
+#[macro_export]
+macro_rules! merge_by {
+	($cmp:expr; $($e:expr),+,) => ({ merge_by!($cmp; $($e),*) });
+	($cmp:expr; $($e:expr),+) => ({
+		let mut temporary: IterList<_> = vec![];
+		$(
+			temporary.push(Box::new($e));
+		);*
+		MergeIter::new_by(temporary, $cmp)
+	});
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{IterList, MultiIter};
+	use super::{Iter, IterList, MultiIter, MultiIterBack, DoubleEndedIterList, MergeIter};
 
 	macro_rules! next {
 		($e:expr; $($n:expr),*,) => ( next!($e; $($n),*) );
@@ -194,4 +624,129 @@ mod tests {
 		check(interleave!((0..5), (0..2), (0..7), (0..10)));
 		check(interleave!((0..5), (0..7), (0..2), (0..10)));
 	}
+
+	#[test]
+	fn dropping_exhausted_source_preserves_order_of_survivors() {
+		// Source A (0..1) exhausts after its first element. Dropping it
+		// must not disturb the relative order of the still-live sources
+		// B (10..13) and C (20..23): C must keep following B, not jump
+		// ahead of it just because it was swapped into A's old slot.
+		let iter = interleave!(0..1, 10..13, 20..23);
+		let got: Vec<i32> = iter.collect();
+		assert_eq!(got, vec![0, 10, 20, 11, 21, 12, 22]);
+	}
+
+	#[test]
+	fn size_hint_sums_exact_sources() {
+		let iter = interleave!((0..5), (0..3));
+		assert_eq!(iter.size_hint(), (8, Some(8)));
+		assert_eq!(iter.exact_len(), Some(8));
+	}
+
+	#[test]
+	fn shortest_size_hint_matches_actual_yield_count() {
+		let iter = interleave_shortest!((0..10), (0..2));
+		assert_eq!(iter.size_hint(), (5, Some(5)));
+		assert_eq!(iter.exact_len(), Some(5));
+		assert_eq!(iter.count(), 5);
+	}
+
+	#[test]
+	fn exact_len_is_none_for_inexact_sources() {
+		let iter = interleave!((0..10).filter(|x| x % 2 == 0));
+		assert_eq!(iter.size_hint(), (0, Some(10)));
+		assert_eq!(iter.exact_len(), None);
+	}
+
+	#[test]
+	fn fused_after_exhaustion() {
+		let mut iter = interleave!(i32; 0..2);
+		assert_eq!(iter.next(), Some(0));
+		assert_eq!(iter.next(), Some(1));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn shortest_stops_at_first_exhausted_source() {
+		let mut iter = interleave_shortest!((0..10), (0..2));
+		next!(iter; 0, 0, 1, 1, 2,);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn many_short_sources_alongside_one_long_source() {
+		let mut items: IterList<i32> = (0..50).map(|_| Box::new(0..1) as Iter<i32>).collect();
+		items.push(Box::new(0..3));
+		let mut iter = MultiIter::new(items);
+		for _ in 0..50 {
+			assert_eq!(iter.next(), Some(0));
+		}
+		assert_eq!(iter.next(), Some(0));
+		assert_eq!(iter.next(), Some(1));
+		assert_eq!(iter.next(), Some(2));
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn weighted_draws_quota_per_round() {
+		let mut iter = interleave_weighted![(0..10, 3), (100..102, 1)];
+		next!(iter; 0, 1, 2, 100, 3, 4, 5, 101, 6, 7, 8, 9,);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn weighted_zero_skips_source() {
+		let mut iter = interleave_weighted![(0..4, 1), (100..200, 0)];
+		next!(iter; 0, 1, 2, 3,);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn back_rev_interleaves_tail_to_head() {
+		let mut iter = interleave_back!(0..4, 10..12).rev();
+		next!(iter; 11, 3, 10, 2, 1, 0,);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn back_meets_in_the_middle_without_double_yield() {
+		let mut iter = interleave_back!(0..5, 10..12);
+		assert_eq!(iter.next(), Some(0));
+		assert_eq!(iter.next_back(), Some(11));
+		assert_eq!(iter.next(), Some(10));
+		assert_eq!(iter.next_back(), Some(4));
+		assert_eq!(iter.next(), Some(1));
+		assert_eq!(iter.next_back(), Some(3));
+		assert_eq!(iter.next(), Some(2));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next_back(), None);
+	}
+
+	#[test]
+	fn merge_sorted() {
+		let mut iter = merge!(vec![1, 4, 7].into_iter(), vec![2, 3, 9].into_iter(), vec![0, 5, 6].into_iter());
+		next!(iter; 0, 1, 2, 3, 4, 5, 6, 7, 9,);
+	}
+
+	#[test]
+	fn merge_ties_break_on_source_index() {
+		let mut iter = merge_by!(
+			|a: &(i32, &str), b: &(i32, &str)| a.0.cmp(&b.0);
+			vec![(1, "s0a"), (1, "s0b")].into_iter(),
+			vec![(1, "s1a"), (1, "s1b")].into_iter()
+		);
+		next!(iter; (1, "s0a"), (1, "s0b"), (1, "s1a"), (1, "s1b"),);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn merge_by_descending() {
+		let mut iter = merge_by!(
+			|a: &i32, b: &i32| b.cmp(a);
+			vec![9, 4, 1].into_iter(),
+			vec![7, 3, 0].into_iter()
+		);
+		next!(iter; 9, 7, 4, 3, 1, 0,);
+	}
 }